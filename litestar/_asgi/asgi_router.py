@@ -4,7 +4,7 @@ import re
 from collections import defaultdict
 from functools import lru_cache
 from traceback import format_exc
-from typing import TYPE_CHECKING, Any, Pattern
+from typing import TYPE_CHECKING, Pattern
 
 from litestar._asgi.routing_trie import validate_node
 from litestar._asgi.routing_trie.mapping import add_route_to_trie
@@ -13,15 +13,15 @@ from litestar._asgi.routing_trie.types import create_node
 from litestar._asgi.utils import get_route_handlers
 from litestar.exceptions import ImproperlyConfiguredException
 from litestar.utils import normalize_path
+from litestar.utils.path import remove_dot_segments
 from litestar.utils.scope.state import ScopeState
 
 if TYPE_CHECKING:
-    from litestar._asgi.routing_trie.types import RouteTrieNode
+    from litestar._asgi.routing_trie.types import RouteResolution, RouteTrieNode
     from litestar.app import Litestar
     from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
     from litestar.routes.base import BaseRoute
     from litestar.types import (
-        ASGIApp,
         ExceptionHandlersMap,
         LifeSpanReceive,
         LifeSpanSend,
@@ -82,27 +82,31 @@ class ASGIRouter:
         scope.setdefault("path_params", {})
 
         path = scope["path"]
-        if root_path := scope.get("root_path", ""):
-            path = path.split(root_path, maxsplit=1)[-1]
-        normalized_path = normalize_path(path)
+        # servers are not consistent in whether they include the root path in the path, so it is only stripped if it is
+        # an actual prefix of the path, ending on a segment boundary
+        if (root_path := scope.get("root_path", "")) and (
+            path == root_path or path.startswith(f"{root_path.rstrip('/')}/")
+        ):
+            path = path[len(root_path) :]
+        # servers pass dot segments through unchanged, so they are resolved here, before the path is matched against
+        # mounts and other prefixes
+        normalized_path = normalize_path(remove_dot_segments(path) if "/." in path else path)
 
         try:
-            asgi_app, route_handler, scope["path"], scope["path_params"], path_template = self.handle_routing(
-                path=normalized_path, method=scope.get("method")
-            )
+            resolution = self.handle_routing(path=normalized_path, method=scope.get("method"))
         except Exception:
             ScopeState.from_scope(scope).exception_handlers = self._app_exception_handlers
             raise
         else:
-            ScopeState.from_scope(scope).exception_handlers = route_handler.resolve_exception_handlers()
-            scope["route_handler"] = route_handler
-            scope["path_template"] = path_template
-        await asgi_app(scope, receive, send)
+            ScopeState.from_scope(scope).exception_handlers = resolution.handler.resolve_exception_handlers()
+            scope["path"] = resolution.path
+            scope["path_params"] = resolution.path_parameters
+            scope["route_handler"] = resolution.handler
+            scope["path_template"] = resolution.path_template
+        await resolution.asgi_app(scope, receive, send)
 
     @lru_cache(1024)  # noqa: B019
-    def handle_routing(
-        self, path: str, method: Method | None
-    ) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any], str]:
+    def handle_routing(self, path: str, method: Method | None) -> RouteResolution:
         """Handle routing for a given path / method combo. This method is meant to allow easy caching.
 
         Args:
@@ -110,7 +114,7 @@ class ASGIRouter:
             method: The scope's method, if any.
 
         Returns:
-            A tuple composed of the ASGIApp of the route, the route handler instance, the resolved and normalized path and any parsed path params.
+            A :class:`RouteResolution` composed of the ASGIApp of the route, the route handler instance, the mount the path resolved to, if any, the resolved and normalized path, any parsed path params and the path template.
         """
         return parse_path_to_route(
             mount_paths_regex=self._mount_paths_regex,
@@ -159,7 +163,18 @@ class ASGIRouter:
 
         validate_node(node=self.root_route_map_node)
         if self._mount_routes:
-            self._mount_paths_regex = re.compile("|".join(sorted(set(self._mount_routes))))  # pyright: ignore
+            # longer mount paths are tried first, so that the most specific of several overlapping mounts wins. Matches
+            # have to end on a segment boundary, i.e. a mount at '/static' must not match '/static-files'.
+            self._mount_paths_regex = re.compile(
+                "|".join(
+                    "/" if mount_path == "/" else rf"{re.escape(mount_path)}(?=/|$)"
+                    for mount_path in sorted(self._mount_routes, key=len, reverse=True)
+                )
+            )
+
+        # previously resolved paths may now resolve to one of the new routes. The cache is shared by all router
+        # instances in the process, so this also drops the entries of other apps, which are simply resolved again.
+        self.handle_routing.cache_clear()
 
     async def lifespan(self, receive: LifeSpanReceive, send: LifeSpanSend) -> None:
         """Handle the ASGI "lifespan" event on application startup and shutdown.
@@ -1,24 +1,35 @@
 from __future__ import annotations
 
 from pathlib import Path
-from typing import TYPE_CHECKING, Any, cast
+from typing import TYPE_CHECKING, Any, Literal, cast
 
 from litestar._asgi.routing_trie.types import (
     ASGIHandlerTuple,
     PathParameterSentinel,
     create_node,
 )
-from litestar._asgi.utils import wrap_in_exception_handler
+from litestar._asgi.utils import get_route_handlers, wrap_in_exception_handler
+from litestar.exceptions import ImproperlyConfiguredException
 from litestar.types.internal_types import PathParameterDefinition
-
-__all__ = ("add_mount_route", "add_route_to_trie", "build_route_middleware_stack", "configure_node")
+from litestar.utils import unique
+
+__all__ = (
+    "add_mount_route",
+    "add_route_to_trie",
+    "build_route_middleware_stack",
+    "configure_generated_options_handler",
+    "configure_node",
+    "merge_path_parameter_definitions",
+    "validate_no_conflicting_handler",
+)
 
 
 if TYPE_CHECKING:
     from litestar._asgi.routing_trie.types import RouteTrieNode
     from litestar.app import Litestar
+    from litestar.handlers.http_handlers import HTTPRouteHandler
     from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
-    from litestar.types import ASGIApp, RouteHandlerType
+    from litestar.types import ASGIApp, Method, RouteHandlerType
 
 
 def add_mount_route(
@@ -135,24 +146,47 @@ def configure_node(
         route: The route that is being added.
         node: The trie node being configured.
 
+    Raises:
+        ImproperlyConfiguredException: If a different handler is already registered on the node for the same HTTP
+            method, or a different websocket handler is already registered on the node.
+
     Returns:
         None
     """
     from litestar.routes import HTTPRoute, WebSocketRoute
+    from litestar.routes.http import GeneratedOptionsHandler
+
+    if isinstance(route, HTTPRoute):
+        route_handlers: dict[Method, HTTPRouteHandler] = {}
+        for method, (handler, _) in route.route_handler_map.items():
+            # a generated OPTIONS handler must not replace one that another route on the node declares explicitly
+            if (
+                isinstance(handler, GeneratedOptionsHandler)
+                and (existing := node.asgi_handlers.get(method))
+                and not isinstance(existing.handler, GeneratedOptionsHandler)
+            ):
+                continue
+            # the whole route is validated before the node is modified, so that a failed registration leaves it intact
+            validate_no_conflicting_handler(app=app, route=route, node=node, key=method, handler=handler)
+            route_handlers[method] = handler
+
+    elif isinstance(route, WebSocketRoute):
+        validate_no_conflicting_handler(app=app, route=route, node=node, key="websocket", handler=route.route_handler)
 
     node.path_template = route.path_format
     if not node.path_parameters:
         node.path_parameters = {}
 
     if isinstance(route, HTTPRoute):
-        for method, handler_mapping in route.route_handler_map.items():
-            handler, _ = handler_mapping
+        for method, handler in route_handlers.items():
             node.asgi_handlers[method] = ASGIHandlerTuple(
                 asgi_app=build_route_middleware_stack(app=app, route=route, route_handler=handler),
                 handler=handler,
             )
             node.path_parameters[method] = tuple(route.path_parameters.values())
 
+        configure_generated_options_handler(node=node)
+
     elif isinstance(route, WebSocketRoute):
         node.asgi_handlers["websocket"] = ASGIHandlerTuple(
             asgi_app=build_route_middleware_stack(app=app, route=route, route_handler=route.route_handler),
@@ -169,6 +203,107 @@ def configure_node(
         node.is_asgi = True
 
 
+def validate_no_conflicting_handler(
+    app: Litestar,
+    route: HTTPRoute | WebSocketRoute,
+    node: RouteTrieNode,
+    key: Method | Literal["websocket"],
+    handler: RouteHandlerType,
+) -> None:
+    """Ensure that no other handler is registered on the node under the given key.
+
+    Different route paths can resolve to the same trie node, e.g. ``/items/{item_id:int}`` and
+    ``/items/{item_id:uuid}``, in which case the handler registered last would silently replace the other one.
+
+    Args:
+        app: The Litestar app instance.
+        route: The route that is being added.
+        node: The trie node being configured.
+        key: The HTTP method or ``websocket``.
+        handler: The route handler that is being registered.
+
+    Raises:
+        ImproperlyConfiguredException: If a different handler is already registered on the node under the given key.
+
+    Returns:
+        None
+    """
+    from litestar.routes.http import GeneratedOptionsHandler
+
+    if (
+        not (existing := node.asgi_handlers.get(key))
+        or existing.handler is handler
+        or isinstance(existing.handler, GeneratedOptionsHandler)
+    ):
+        return
+
+    existing_path = next(
+        (
+            existing_route.path
+            for existing_route in app.routes
+            if any(route_handler is existing.handler for route_handler in get_route_handlers(existing_route))
+        ),
+        node.path_template,
+    )
+    raise ImproperlyConfiguredException(
+        f"Handler {handler!s} for path {route.path!r} conflicts with handler {existing.handler!s} for path "
+        f"{existing_path!r}: both paths resolve to the same route and are registered for {key!s}"
+    )
+
+
+def configure_generated_options_handler(node: RouteTrieNode) -> None:
+    """Make a generated OPTIONS handler on the node reflect all HTTP methods registered on the node.
+
+    The ``Allow`` header lists the methods of every route on the node, and the path parameters are parsed so that any
+    value accepted by one of the node's methods is accepted.
+
+    Args:
+        node: The trie node being configured.
+
+    Returns:
+        None
+    """
+    from litestar.routes.http import GeneratedOptionsHandler
+
+    if not (options := node.asgi_handlers.get("OPTIONS")) or not isinstance(options.handler, GeneratedOptionsHandler):
+        return
+
+    methods = [method for method in node.asgi_handlers if method not in {"OPTIONS", "asgi", "websocket"}]
+    options.handler.allowed_methods = {"OPTIONS", *methods}
+    if methods:
+        node.path_parameters["OPTIONS"] = tuple(
+            merge_path_parameter_definitions(definitions)
+            for definitions in zip(*(node.path_parameters[method] for method in methods))
+        )
+
+
+def merge_path_parameter_definitions(definitions: tuple[PathParameterDefinition, ...]) -> PathParameterDefinition:
+    """Merge the definitions different routes on a node give for the same path parameter into one.
+
+    Args:
+        definitions: The path parameter definitions.
+
+    Returns:
+        A path parameter definition that accepts a value if any of the given definitions accepts it.
+    """
+    parsers = unique([definition.parser for definition in definitions])
+    if None in parsers:
+        return definitions[0]._replace(parser=None)
+    if len(parsers) == 1:
+        return definitions[0]
+
+    def parser(value: str) -> Any:
+        for parse in parsers[:-1]:
+            try:
+                return parse(value)  # type: ignore[misc]
+            # parsing a Decimal raises InvalidOperation, which is an ArithmeticError
+            except (ArithmeticError, ValueError):
+                continue
+        return parsers[-1](value)  # type: ignore[misc]
+
+    return definitions[0]._replace(parser=parser)
+
+
 def build_route_middleware_stack(
     app: Litestar,
     route: HTTPRoute | WebSocketRoute | ASGIRoute,
@@ -1,10 +1,11 @@
 from __future__ import annotations
 
+import re
 from functools import lru_cache
 from typing import TYPE_CHECKING, Any, Pattern
 
-from litestar._asgi.routing_trie.types import PathParameterSentinel
-from litestar.exceptions import MethodNotAllowedException, NotFoundException
+from litestar._asgi.routing_trie.types import PathParameterSentinel, RouteResolution
+from litestar.exceptions import ClientException, MethodNotAllowedException, NotFoundException
 from litestar.utils import normalize_path
 
 __all__ = ("parse_node_handlers", "parse_path_params", "parse_path_to_route", "traverse_route_map")
@@ -12,16 +13,22 @@ __all__ = ("parse_node_handlers", "parse_path_params", "parse_path_to_route", "t
 
 if TYPE_CHECKING:
     from litestar._asgi.routing_trie.types import ASGIHandlerTuple, RouteTrieNode
-    from litestar.types import ASGIApp, Method, RouteHandlerType
+    from litestar.types import Method
     from litestar.types.internal_types import PathParameterDefinition
 
 
+control_characters_regex = re.compile(r"[\x00-\x1f\x7f]")
+
+
 def traverse_route_map(
     root_node: RouteTrieNode,
     path: str,
 ) -> tuple[RouteTrieNode, list[str], str]:
     """Traverses the application route mapping and retrieves the correct node for the request url.
 
+    Static path components take precedence over path parameters. If traversal down a static branch dead-ends, it
+    backtracks and tries the path parameter branch that was passed over instead.
+
     Args:
         root_node: The root trie node.
         path: The request's path.
@@ -35,28 +42,43 @@ def traverse_route_map(
     current_node = root_node
     path_params: list[str] = []
     path_components = [p for p in path.split("/") if p]
-
-    for i, component in enumerate(path_components):
-        if component in current_node.child_keys:
-            current_node = current_node.children[component]
+    components_count = len(path_components)
+    # path parameter branches that were passed over in favour of a static child, stored as tuples of the component
+    # index, the node and the number of path parameter values collected up to that point.
+    fallbacks: list[tuple[int, RouteTrieNode, int]] = []
+    prefer_static = True
+    i = 0
+
+    while True:
+        if i == components_count:
+            if current_node.asgi_handlers:
+                return current_node, path_params, path
+
+        elif prefer_static and path_components[i] in current_node.child_keys:
+            if current_node.is_path_param_node:
+                fallbacks.append((i, current_node, len(path_params)))
+            current_node = current_node.children[path_components[i]]
+            i += 1
             continue
 
-        if current_node.is_path_param_node:
+        elif current_node.is_path_param_node:
+            prefer_static = True
             current_node = current_node.children[PathParameterSentinel]
 
             if current_node.is_path_type:
                 path_params.append(normalize_path("/".join(path_components[i:])))
-                break
-
-            path_params.append(component)
+                i = components_count
+            else:
+                path_params.append(path_components[i])
+                i += 1
             continue
 
-        raise NotFoundException()
-
-    if not current_node.asgi_handlers:
-        raise NotFoundException()
+        if not fallbacks:
+            raise NotFoundException()
 
-    return current_node, path_params, path
+        i, current_node, path_params_count = fallbacks.pop()
+        del path_params[path_params_count:]
+        prefer_static = False
 
 
 def parse_node_handlers(
@@ -70,7 +92,9 @@ def parse_node_handlers(
         method: The scope's method.
 
     Raises:
-        KeyError: If no matching method is found.
+        MethodNotAllowedException: If no handler is registered for the given HTTP method. The exception carries an
+            ``Allow`` header listing the methods that are registered on the node.
+        KeyError: If no websocket handler is found.
 
     Returns:
         An ASGI Handler tuple.
@@ -79,7 +103,12 @@ def parse_node_handlers(
     if node.is_asgi:
         return node.asgi_handlers["asgi"]
     if method:
-        return node.asgi_handlers[method]
+        try:
+            return node.asgi_handlers[method]
+        except KeyError as e:
+            allowed_methods = sorted(key for key in node.asgi_handlers if key not in {"asgi", "websocket"})
+            # an empty value is valid for a node that only has a websocket handler, see RFC 9110 §10.2.1
+            raise MethodNotAllowedException(headers={"Allow": ", ".join(allowed_methods)}) from e
     return node.asgi_handlers["websocket"]
 
 
@@ -112,7 +141,7 @@ def parse_path_to_route(
     path: str,
     plain_routes: set[str],
     root_node: RouteTrieNode,
-) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any], str]:
+) -> RouteResolution:
     """Given a scope object, retrieve the asgi_handlers and is_mount boolean values from correct trie node.
 
     Args:
@@ -124,17 +153,30 @@ def parse_path_to_route(
         mount_paths_regex: A compiled regex to match the mount routes.
 
     Raises:
+        ClientException: If the path contains NUL or other control characters.
         MethodNotAllowedException: if no matching method is found.
         NotFoundException: If no correlating node is found or if path params can not be parsed into values according to the node definition.
 
     Returns:
-        A tuple containing the stack of middlewares and the route handler that is wrapped by it.
+        A :class:`RouteResolution` containing the stack of middlewares, the route handler that is wrapped by it, the
+        mount the path resolved to, if any, and the resolved path, path parameters and path template.
     """
+    if control_characters_regex.search(path):
+        raise ClientException("Request path contains invalid characters")
 
     try:
         if path in plain_routes:
-            asgi_app, handler = parse_node_handlers(node=root_node.children[path], method=method)
-            return asgi_app, handler, path, {}, path
+            node = root_node.children[path]
+            asgi_app, handler = parse_node_handlers(node=node, method=method)
+            return RouteResolution(
+                asgi_app=asgi_app,
+                handler=handler,
+                is_asgi=node.is_asgi,
+                mount_path=None,
+                path=path,
+                path_parameters={},
+                path_template=path,
+            )
 
         if mount_paths_regex and (match := mount_paths_regex.match(path)):
             mount_path = path[: match.end()]
@@ -152,7 +194,15 @@ def parse_path_to_route(
                 remaining_path = remaining_path or "/"
                 if not mount_node.is_static:
                     remaining_path = remaining_path if remaining_path.endswith("/") else f"{remaining_path}/"
-                return asgi_app, handler, remaining_path, {}, root_node.path_template
+                return RouteResolution(
+                    asgi_app=asgi_app,
+                    handler=handler,
+                    is_asgi=mount_node.is_asgi,
+                    mount_path=mount_path,
+                    path=remaining_path,
+                    path_parameters={},
+                    path_template=root_node.path_template,
+                )
 
         node, path_parameters, path = traverse_route_map(
             root_node=root_node,
@@ -162,12 +212,14 @@ def parse_path_to_route(
         key = method or ("asgi" if node.is_asgi else "websocket")
         parsed_path_parameters = parse_path_params(node.path_parameters[key], tuple(path_parameters))
 
-        return (
-            asgi_app,
-            handler,
-            path,
-            parsed_path_parameters,
-            node.path_template,
+        return RouteResolution(
+            asgi_app=asgi_app,
+            handler=handler,
+            is_asgi=node.is_asgi,
+            mount_path=None,
+            path=path,
+            path_parameters=parsed_path_parameters,
+            path_template=node.path_template,
         )
     except KeyError as e:
         raise MethodNotAllowedException() from e
@@ -1,9 +1,9 @@
 from __future__ import annotations
 
 from dataclasses import dataclass
-from typing import TYPE_CHECKING, Literal, NamedTuple
+from typing import TYPE_CHECKING, Any, Literal, NamedTuple
 
-__all__ = ("ASGIHandlerTuple", "PathParameterSentinel", "RouteTrieNode", "create_node")
+__all__ = ("ASGIHandlerTuple", "PathParameterSentinel", "RouteResolution", "RouteTrieNode", "create_node")
 
 
 if TYPE_CHECKING:
@@ -24,6 +24,25 @@ class ASGIHandlerTuple(NamedTuple):
     """The route handler instance."""
 
 
+class RouteResolution(NamedTuple):
+    """The result of resolving a path and method against the route trie."""
+
+    asgi_app: ASGIApp
+    """The ASGI stack to dispatch the connection to."""
+    handler: RouteHandlerType
+    """The route handler instance."""
+    is_asgi: bool
+    """Whether the route handler is an ASGI handler."""
+    mount_path: str | None
+    """The path of the mount the path resolved to, if any."""
+    path: str
+    """The resolved path. For mount routes this is the path relative to the mount."""
+    path_parameters: dict[str, Any]
+    """The parsed path parameter values."""
+    path_template: str
+    """The path template of the matched route."""
+
+
 @dataclass(unsafe_hash=True)
 class RouteTrieNode:
     """A radix trie node."""
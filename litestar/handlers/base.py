@@ -48,6 +48,7 @@ class BaseRouteHandler:
     """
 
     __slots__ = (
+        "_declared_paths",
         "_fn",
         "_parsed_data_field",
         "_parsed_fn_signature",
@@ -147,15 +148,26 @@ class BaseRouteHandler:
         )
         self.type_decoders = type_decoders
         self.type_encoders = type_encoders
-        self.paths = (
-            {normalize_path(p) for p in path} if path and isinstance(path, list) else {normalize_path(path or "/")}  # type: ignore[arg-type]
-        )
+        declared_paths: list[str] = path if path and isinstance(path, list) else [path or "/"]  # type: ignore[list-item]
+        self.paths = {normalize_path(p) for p in declared_paths}
+        self._declared_paths = {normalize_path(p): p for p in declared_paths}
 
     def __call__(self, fn: AsyncAnyCallable) -> Self:
         """Replace a function with itself."""
         self._fn = fn
         return self
 
+    def get_declared_path(self, path: str) -> str:
+        """Get a path of the handler as it was declared, before being normalized.
+
+        Args:
+            path: One of the normalized :attr:`paths` of the handler.
+
+        Returns:
+            The path as declared, or ``path`` if the handler does not declare it.
+        """
+        return self._declared_paths.get(path, path)
+
     @property
     def handler_id(self) -> str:
         """A unique identifier used for generation of DTOs."""
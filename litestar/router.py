@@ -11,6 +11,7 @@ from litestar.handlers.asgi_handlers import ASGIRouteHandler
 from litestar.handlers.http_handlers import HTTPRouteHandler
 from litestar.handlers.websocket_handlers import WebsocketListener, WebsocketRouteHandler
 from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
+from litestar.routes.http import GeneratedOptionsHandler
 from litestar.types.empty import Empty
 from litestar.utils import find_index, is_class_and_subclass, join_paths, normalize_path, unique
 from litestar.utils.signature import add_types_to_signature_namespace
@@ -233,6 +234,17 @@ class Router:
                         if isinstance(handler, HTTPRouteHandler)
                     ]
                 ):
+                    # the OPTIONS handlers generated for the routes being merged are replaced by one that is generated
+                    # for the merged route, unless one of the handlers declares OPTIONS explicitly
+                    http_handlers = [
+                        handler for handler in http_handlers if not isinstance(handler, GeneratedOptionsHandler)
+                    ]
+                    existing_handlers = [
+                        handler for handler in existing_handlers if not isinstance(handler, GeneratedOptionsHandler)
+                    ]
+                    self._validate_no_conflicting_handlers(
+                        path=path, route_handlers=http_handlers, existing_route_handlers=existing_handlers
+                    )
                     http_handlers.extend(existing_handlers)
                     existing_route_index = find_index(self.routes, lambda x: x.path == path)  # noqa: B023
 
@@ -300,6 +312,57 @@ class Router:
             for path in value.paths
         }
 
+    def _validate_no_conflicting_handlers(
+        self,
+        path: str,
+        route_handlers: list[HTTPRouteHandler],
+        existing_route_handlers: list[HTTPRouteHandler],
+    ) -> None:
+        """Ensure that handlers registered for a path do not declare a method an existing handler for the path declares.
+
+        Different spellings of a path, e.g. with a trailing slash, are normalized to the same path, so the error names
+        the paths as declared by the handlers.
+
+        Args:
+            path: The normalized path.
+            route_handlers: The handlers that are being registered.
+            existing_route_handlers: The handlers that are already registered for the path.
+
+        Raises:
+            ImproperlyConfiguredException: If a handler declares a method an existing handler declares.
+
+        Returns:
+            None
+        """
+        for route_handler in route_handlers:
+            for existing_route_handler in existing_route_handlers:
+                if methods := route_handler.http_methods & existing_route_handler.http_methods:
+                    raise ImproperlyConfiguredException(
+                        f"Handler {route_handler!s} for path {self._get_declared_path(route_handler, path)!r} "
+                        f"conflicts with handler {existing_route_handler!s} for path "
+                        f"{self._get_declared_path(existing_route_handler, path)!r}: both paths resolve to the same "
+                        f"route and are registered for {', '.join(sorted(methods))}"
+                    )
+
+    def _get_declared_path(self, route_handler: HTTPRouteHandler, path: str) -> str:
+        """Get the path of a handler that resolves to the given path, as declared by the handler.
+
+        Args:
+            route_handler: The route handler.
+            path: The normalized path, including the path of the router.
+
+        Returns:
+            The path as declared, or ``path`` if the handler was registered through another router.
+        """
+        return next(
+            (
+                route_handler.get_declared_path(handler_path)
+                for handler_path in route_handler.paths
+                if join_paths([self.path, handler_path]) == path
+            ),
+            path,
+        )
+
     def _validate_registration_value(self, value: ControllerRouterHandler) -> RouteHandlerType | Router:
         """Ensure values passed to the register method are supported."""
         if is_class_and_subclass(value, Controller):
@@ -22,6 +22,19 @@ if TYPE_CHECKING:
     from litestar.types import ASGIApp, HTTPScope, Method, Receive, Scope, Send
 
 
+class GeneratedOptionsHandler(HTTPRouteHandler):
+    """The OPTIONS handler created by :class:`HTTPRoute` for a route that does not declare one.
+
+    Several routes can resolve to the same trie node, in which case their generated OPTIONS handlers share the node and
+    the ``Allow`` header has to list the methods of all of them.
+    """
+
+    __slots__ = ("allowed_methods",)
+
+    allowed_methods: set[str]
+    """The methods listed in the ``Allow`` header of the response."""
+
+
 class HTTPRoute(BaseRoute):
     """An HTTP route, capable of handling multiple ``HTTPRouteHandler``\\ s."""  # noqa: D301
 
@@ -47,6 +60,7 @@ class HTTPRoute(BaseRoute):
             methods.append("OPTIONS")
             options_handler = self.create_options_handler(path)
             options_handler.owner = route_handlers[0].owner
+            options_handler.allowed_methods = set(methods)
             route_handlers.append(options_handler)
 
         self.route_handlers = route_handlers
@@ -231,13 +245,19 @@ class HTTPRoute(BaseRoute):
 
         return cached_response
 
-    def create_options_handler(self, path: str) -> HTTPRouteHandler:
+    def create_options_handler(self, path: str) -> GeneratedOptionsHandler:
         """Args:
             path: The route path
 
         Returns:
             An HTTP route handler for OPTIONS requests.
         """
+        handler = GeneratedOptionsHandler(
+            path=path,
+            http_method=[HttpMethod.OPTIONS],
+            include_in_schema=False,
+            sync_to_thread=False,
+        )
 
         def options_handler(scope: Scope) -> Response:
             """Handler function for OPTIONS requests.
@@ -251,13 +271,8 @@ class HTTPRoute(BaseRoute):
             return Response(
                 content=None,
                 status_code=HTTP_204_NO_CONTENT,
-                headers={"Allow": ", ".join(sorted(self.methods))},  # pyright: ignore
+                headers={"Allow": ", ".join(sorted(handler.allowed_methods))},
                 media_type=MediaType.TEXT,
             )
 
-        return HTTPRouteHandler(
-            path=path,
-            http_method=[HttpMethod.OPTIONS],
-            include_in_schema=False,
-            sync_to_thread=False,
-        )(options_handler)
+        return handler(options_handler)
@@ -3,7 +3,7 @@ from __future__ import annotations
 import re
 from typing import Iterable
 
-__all__ = ("join_paths", "normalize_path")
+__all__ = ("join_paths", "normalize_path", "remove_dot_segments")
 
 
 multi_slash_pattern = re.compile("//+")
@@ -33,3 +33,24 @@ def join_paths(paths: Iterable[str]) -> str:
         A normalized joined path string.
     """
     return normalize_path("/".join(paths))
+
+
+def remove_dot_segments(path: str) -> str:
+    """Resolve the ``.`` and ``..`` segments of a path, see RFC 3986, section 5.2.4.
+
+    ``..`` segments never ascend above the root. Empty segments are dropped, as they are by :func:`normalize_path`.
+
+    Args:
+        path: Path string
+
+    Returns:
+        Path string
+    """
+    segments: list[str] = []
+    for segment in path.split("/"):
+        if segment == "..":
+            if segments:
+                segments.pop()
+        elif segment and segment != ".":
+            segments.append(segment)
+    return "/" + "/".join(segments)
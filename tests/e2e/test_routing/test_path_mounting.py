@@ -8,11 +8,12 @@ from _pytest.monkeypatch import MonkeyPatch
 from litestar import Litestar, MediaType, asgi, get, websocket
 from litestar.exceptions import ImproperlyConfiguredException
 from litestar.response.base import ASGIResponse
-from litestar.status_codes import HTTP_200_OK
+from litestar.status_codes import HTTP_200_OK, HTTP_404_NOT_FOUND
 from litestar.testing import create_test_client
 
 if TYPE_CHECKING:
     from litestar.connection import WebSocket
+    from litestar.handlers import ASGIRouteHandler
     from litestar.types import Receive, Scope, Send
 
 
@@ -56,6 +57,45 @@ def test_supports_mounting() -> None:
         assert response.text == "/not/mount"
 
 
+def test_overlapping_mounts_resolve_to_most_specific_mount() -> None:
+    def create_mount(path: str) -> "ASGIRouteHandler":
+        @asgi(path, is_mount=True)
+        async def asgi_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+            response = ASGIResponse(body=f"{path}:{scope['path']}".encode(), media_type=MediaType.TEXT)
+            await response(scope, receive, send)
+
+        return asgi_handler
+
+    with create_test_client(
+        route_handlers=[
+            create_mount("/static"),
+            create_mount("/static/admin/assets"),
+            create_mount("/static/admin"),
+        ]
+    ) as client:
+        response = client.get("/static")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "/static:/"
+
+        response = client.get("/static/file.txt")
+        assert response.text == "/static:/file.txt/"
+
+        response = client.get("/static/admin")
+        assert response.text == "/static/admin:/"
+
+        response = client.get("/static/admin/index.html")
+        assert response.text == "/static/admin:/index.html/"
+
+        response = client.get("/static/admin/assets/app.js")
+        assert response.text == "/static/admin/assets:/app.js/"
+
+        response = client.get("/static/administrator")
+        assert response.text == "/static:/administrator/"
+
+        response = client.get("/static-files")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+
 def test_supports_sub_routes_below_asgi_handlers() -> None:
     @asgi("/base/sub/path")
     async def asgi_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
@@ -1,11 +1,12 @@
 from pathlib import Path
 from typing import Any, Callable, List, Optional, Type
+from uuid import UUID
 
 import httpx
 import pytest
 from _pytest.monkeypatch import MonkeyPatch
 
-from litestar import Controller, MediaType, Router, delete, get, post
+from litestar import Controller, MediaType, Router, WebSocket, delete, get, post, put, websocket
 from litestar.status_codes import (
     HTTP_200_OK,
     HTTP_204_NO_CONTENT,
@@ -163,6 +164,18 @@ async def test_http_route_raises_for_unsupported_method(anyio_backend: str) -> N
     with create_test_client(route_handlers=[my_get_handler, my_post_handler]) as client:
         response = client.delete("/")
         assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+        assert response.headers["allow"] == "GET, OPTIONS, POST"
+
+
+def test_http_request_to_websocket_route_sends_empty_allow_header() -> None:
+    @websocket("/ws")
+    async def websocket_handler(socket: WebSocket) -> None:
+        await socket.close()
+
+    with create_test_client(route_handlers=[websocket_handler]) as client:
+        response = client.get("/ws")
+        assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+        assert response.headers["allow"] == ""
 
 
 def test_path_order() -> None:
@@ -298,6 +311,42 @@ def test_base_path_param_resolution_2() -> None:
         assert response.status_code == HTTP_404_NOT_FOUND
 
 
+def test_path_parameter_types_per_method() -> None:
+    # handlers for different methods on the same node may declare different types for the same parameter
+    @get("/items/{item_id:int}", media_type=MediaType.TEXT)
+    def get_item(item_id: int) -> str:
+        return f"int:{item_id}"
+
+    @put("/items/{item_id:uuid}", media_type=MediaType.TEXT)
+    def put_item(item_id: UUID) -> str:
+        return f"uuid:{item_id}"
+
+    item_uuid = "c892496f-b1fd-4b91-bdb8-b46f92df1716"
+
+    with create_test_client([get_item, put_item]) as client:
+        response = client.get("/items/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "int:1"
+
+        response = client.put(f"/items/{item_uuid}")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == f"uuid:{item_uuid}"
+
+        response = client.get(f"/items/{item_uuid}")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+        for item_id in ("1", item_uuid):
+            response = client.options(f"/items/{item_id}")
+            assert response.status_code == HTTP_204_NO_CONTENT
+            assert response.headers["allow"] == "GET, OPTIONS, PUT"
+
+        response = client.options("/items/abc")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+        response = client.put("/items/1")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+
 @pytest.mark.parametrize(
     "server_command",
     [
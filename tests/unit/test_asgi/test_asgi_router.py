@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import re
 from contextlib import asynccontextmanager
 from typing import TYPE_CHECKING, AsyncGenerator, Callable
 from unittest.mock import AsyncMock, MagicMock, call
@@ -8,9 +9,13 @@ import anyio
 import pytest
 from pytest_mock import MockerFixture
 
-from litestar import Litestar, asgi, get
+from litestar import HttpMethod, Litestar, MediaType, Router, asgi, get, post, put, route, websocket
 from litestar._asgi.asgi_router import ASGIRouter
+from litestar._asgi.routing_trie.types import PathParameterSentinel, RouteResolution
+from litestar.connection import WebSocket
 from litestar.exceptions import ImproperlyConfiguredException, NotFoundException
+from litestar.response.base import ASGIResponse
+from litestar.status_codes import HTTP_200_OK
 from litestar.testing import TestClient, create_test_client
 from litestar.types.empty import Empty
 from litestar.utils.helpers import get_exception_group
@@ -32,6 +37,181 @@ def test_add_mount_route_disallow_path_parameter() -> None:
         Litestar(route_handlers=[asgi("/mount-path", is_static=True)(handler), asgi("/mount-path/{id:str}")(handler)])
 
 
+@pytest.mark.parametrize("paths", [("/ws", "/ws"), ("/ws/{id:int}", "/ws/{name:str}")])
+def test_add_websocket_route_disallow_duplicate_handlers(paths: tuple[str, str]) -> None:
+    @websocket(paths[0])
+    async def first_handler(socket: WebSocket) -> None:
+        return None
+
+    @websocket(paths[1])
+    async def second_handler(socket: WebSocket) -> None:
+        return None
+
+    with pytest.raises(ImproperlyConfiguredException, match="first_handler"):
+        Litestar(route_handlers=[first_handler, second_handler])
+
+
+def test_add_http_route_disallow_conflicting_handlers_for_method() -> None:
+    @get("/items/{item_id:int}")
+    async def first_handler(item_id: int) -> None:
+        return None
+
+    @get("/items/{item_id:str}")
+    async def second_handler(item_id: str) -> None:
+        return None
+
+    with pytest.raises(ImproperlyConfiguredException, match="first_handler"):
+        Litestar(route_handlers=[first_handler, second_handler])
+
+
+def test_add_http_route_disallow_conflicting_options_handlers() -> None:
+    @route("/items/{item_id:int}", http_method=[HttpMethod.OPTIONS])
+    async def first_handler(item_id: int) -> None:
+        return None
+
+    @route("/items/{item_id:str}", http_method=[HttpMethod.OPTIONS])
+    async def second_handler(item_id: str) -> None:
+        return None
+
+    with pytest.raises(ImproperlyConfiguredException, match="first_handler"):
+        Litestar(route_handlers=[first_handler, second_handler])
+
+
+def test_add_http_route_conflict_leaves_node_unchanged() -> None:
+    @get("/items/{item_id:int}", media_type=MediaType.TEXT)
+    def first_handler(item_id: int) -> str:
+        return "first"
+
+    @post("/items/{item_id:str}", media_type=MediaType.TEXT)
+    def second_post_handler(item_id: str) -> str:
+        return "second"
+
+    @get("/items/{item_id:str}")
+    def second_get_handler(item_id: str) -> str:
+        return "second"
+
+    app = Litestar(route_handlers=[first_handler])
+    with pytest.raises(ImproperlyConfiguredException, match="first_handler"):
+        app.register(Router(path="/", route_handlers=[second_post_handler, second_get_handler]))
+
+    node = app.asgi_router.root_route_map_node.children["items"].children[PathParameterSentinel]
+    assert set(node.asgi_handlers) == {"GET", "OPTIONS"}
+    assert node.asgi_handlers["GET"].handler.handler_name == "first_handler"
+
+
+@pytest.mark.parametrize("register_explicit_first", [True, False])
+def test_generated_options_handler_does_not_replace_explicit_one(register_explicit_first: bool) -> None:
+    @route("/items/{item_id:int}", http_method=[HttpMethod.OPTIONS], media_type=MediaType.TEXT)
+    def options_handler(item_id: int) -> str:
+        return "explicit"
+
+    @put("/items/{item_id:str}")
+    def put_handler(item_id: str) -> None:
+        return None
+
+    route_handlers = [options_handler, put_handler] if register_explicit_first else [put_handler, options_handler]
+    with create_test_client(route_handlers) as client:
+        response = client.options("/items/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "explicit"
+
+
+@pytest.mark.parametrize(
+    "paths",
+    [
+        ("/users/{user_id:int}", "/users/{user_id : int}"),
+        ("/users/{user_id:int}", "/users/{user_id:int}/"),
+        ("/users/{user_id:int}", "//users//{user_id:int}"),
+    ],
+)
+def test_add_http_route_disallow_equivalent_paths_for_method(paths: tuple[str, str]) -> None:
+    @get(paths[0])
+    async def first_handler(user_id: int) -> None:
+        return None
+
+    @get(paths[1])
+    async def second_handler(user_id: int) -> None:
+        return None
+
+    with pytest.raises(
+        ImproperlyConfiguredException, match=rf"{re.escape(repr(paths[1]))}.*{re.escape(repr(paths[0]))}"
+    ):
+        Litestar(route_handlers=[first_handler, second_handler])
+
+
+@pytest.mark.parametrize(
+    "request_path, expected_text",
+    [
+        ("/api/users", "users"),
+        ("/users", "users"),
+        ("/apiary", "apiary"),
+        ("/v1/api/users", "nested"),
+    ],
+)
+def test_root_path_only_stripped_as_prefix(request_path: str, expected_text: str) -> None:
+    @get("/users")
+    async def users_handler() -> str:
+        return "users"
+
+    @get("/apiary")
+    async def apiary_handler() -> str:
+        return "apiary"
+
+    @get("/v1/api/users")
+    async def nested_handler() -> str:
+        return "nested"
+
+    with create_test_client([users_handler, apiary_handler, nested_handler], root_path="/api") as client:
+        response = client.get(request_path)
+        assert response.text == expected_text
+
+
+def test_handle_routing_returns_route_resolution() -> None:
+    @get("/users/{user_id:int}")
+    async def handler(user_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[handler])
+    resolution = app.asgi_router.handle_routing(path="/users/1", method="GET")
+
+    assert isinstance(resolution, RouteResolution)
+    assert str(resolution.handler) == str(handler)
+    assert resolution.path == "/users/1"
+    assert resolution.path_parameters == {"user_id": 1}
+    assert resolution.path_template == "/users/{user_id}"
+    assert resolution.is_asgi is False
+    assert resolution.mount_path is None
+
+
+def test_handle_routing_returns_mount_path() -> None:
+    @asgi("/static", is_mount=True)
+    async def mount_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    app = Litestar(route_handlers=[mount_handler])
+    resolution = app.asgi_router.handle_routing(path="/static/css/app.css", method="GET")
+
+    assert resolution.is_asgi is True
+    assert resolution.mount_path == "/static"
+    assert resolution.path == "/css/app.css/"
+
+
+def test_handle_routing_cache_cleared_on_route_registration() -> None:
+    @asgi("/", is_mount=True)
+    async def mount_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        await ASGIResponse(body=b"mount")(scope, receive, send)
+
+    @get("/new")
+    async def new_handler() -> str:
+        return "new"
+
+    with create_test_client([mount_handler]) as client:
+        assert client.get("/new").text == "mount"
+
+        client.app.register(new_handler)
+        assert client.get("/new").text == "new"
+
+
 class _LifeSpanCallable:
     def __init__(self, should_raise: bool = False) -> None:
         self.called = False
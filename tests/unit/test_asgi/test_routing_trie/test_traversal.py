@@ -1,8 +1,10 @@
 from typing import Any
 
+import pytest
+
 from litestar import Router, asgi, get
 from litestar.response.base import ASGIResponse
-from litestar.status_codes import HTTP_404_NOT_FOUND
+from litestar.status_codes import HTTP_400_BAD_REQUEST, HTTP_404_NOT_FOUND
 from litestar.testing import create_test_client
 
 
@@ -91,3 +93,54 @@ def test_parse_path_to_route_mounted_app_path_router() -> None:
 
         response = client.get("/base/sub/unknown/foobar/")
         assert response.status_code == HTTP_404_NOT_FOUND
+
+
+def test_parse_path_to_route_backtracks_from_static_dead_end() -> None:
+    @get("/{org:str}/users/me")
+    async def me_handler(org: str) -> str:
+        return f"me:{org}"
+
+    @get("/{org:str}/users/{user_id:str}/posts")
+    async def posts_handler(org: str, user_id: str) -> str:
+        return f"posts:{org}:{user_id}"
+
+    with create_test_client([me_handler, posts_handler]) as client:
+        response = client.get("/acme/users/me")
+        assert response.text == "me:acme"
+
+        response = client.get("/acme/users/123/posts")
+        assert response.text == "posts:acme:123"
+
+        response = client.get("/acme/users/me/posts")
+        assert response.text == "posts:acme:me"
+
+        response = client.get("/acme/users/me/comments")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+
+def test_parse_path_to_route_backtracking_discards_path_params_of_dead_end() -> None:
+    @get("/{a:str}/x/{b:str}/y")
+    async def static_handler(a: str, b: str) -> str:
+        return f"static:{a}:{b}"
+
+    @get("/{a:str}/{c:str}/{d:str}/z")
+    async def parametrized_handler(a: str, c: str, d: str) -> str:
+        return f"parametrized:{a}:{c}:{d}"
+
+    with create_test_client([static_handler, parametrized_handler]) as client:
+        response = client.get("/1/x/2/y")
+        assert response.text == "static:1:2"
+
+        response = client.get("/1/x/2/z")
+        assert response.text == "parametrized:1:x:2"
+
+
+@pytest.mark.parametrize("path", ["/foo%00bar", "/foo%0Abar", "/foo/%1F", "/%7F"])
+def test_parse_path_to_route_rejects_control_characters(path: str) -> None:
+    @get("/{name:str}")
+    async def handler(name: str) -> str:
+        return name
+
+    with create_test_client([handler]) as client:
+        response = client.get(path)
+        assert response.status_code == HTTP_400_BAD_REQUEST
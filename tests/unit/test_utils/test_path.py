@@ -1,6 +1,6 @@
 import pytest
 
-from litestar.utils.path import join_paths, normalize_path
+from litestar.utils.path import join_paths, normalize_path, remove_dot_segments
 
 
 @pytest.mark.parametrize(
@@ -45,3 +45,20 @@ def test_join_single() -> None:
 )
 def test_normalize_path(base: str, expected: str) -> None:
     assert normalize_path(base) == expected
+
+
+@pytest.mark.parametrize(
+    "path, expected",
+    [
+        ("/foo/bar", "/foo/bar"),
+        ("/foo/./bar", "/foo/bar"),
+        ("/foo/../bar", "/bar"),
+        ("/foo/bar/..", "/foo"),
+        ("/../../foo", "/foo"),
+        ("//foo///bar", "/foo/bar"),
+        ("/foo/.bar/..baz", "/foo/.bar/..baz"),
+        ("/..", "/"),
+    ],
+)
+def test_remove_dot_segments(path: str, expected: str) -> None:
+    assert remove_dot_segments(path) == expected